@@ -1,4 +1,4 @@
-use std::ffi::OsStr;
+use std::ffi::{OsStr, OsString};
 use std::path::Path;
 
 use crate::{AsRaw, Context, Device, FromRawWithContext};
@@ -146,6 +146,21 @@ impl Enumerator {
             entry: unsafe { crate::ffi::udev_enumerate_get_list_entry(self.enumerator) }
         })
     }
+
+    /// Scans for the kernel subsystems and their `drivers` entries currently available.
+    ///
+    /// Unlike `scan_devices`, the returned entries are not resolved to `Device`s: they are the
+    /// raw names reported by udev (e.g. `block`, `tty`, `drivers/usb`).
+    pub fn scan_subsystems(&mut self) -> crate::Result<Entries> {
+        crate::util::errno_to_result(unsafe {
+            crate::ffi::udev_enumerate_scan_subsystems(self.enumerator)
+        })?;
+
+        Ok(Entries {
+            _enumerator: self.clone(),
+            entry: unsafe { crate::ffi::udev_enumerate_get_list_entry(self.enumerator) }
+        })
+    }
 }
 
 
@@ -179,3 +194,32 @@ impl Iterator for Devices {
         (0, None)
     }
 }
+
+/// Iterator over the subsystem/driver names produced by `scan_subsystems`.
+pub struct Entries {
+    // Kept alive only to hold the enumerator's reference count for as long as `entry` is walked.
+    _enumerator: Enumerator,
+    entry: *mut crate::ffi::udev_list_entry
+}
+
+impl Iterator for Entries {
+    type Item = OsString;
+
+    fn next(&mut self) -> Option<OsString> {
+        if self.entry.is_null() {
+            return None;
+        }
+
+        let name = unsafe {
+            crate::util::ptr_to_os_str_unchecked(crate::ffi::udev_list_entry_get_name(self.entry))
+        }.to_os_string();
+
+        self.entry = unsafe { crate::ffi::udev_list_entry_get_next(self.entry) };
+
+        Some(name)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
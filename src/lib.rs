@@ -0,0 +1,12 @@
+mod context;
+mod enumerator;
+mod monitor;
+mod util;
+mod watcher;
+
+pub use crate::context::Context;
+pub use crate::enumerator::{Devices, Entries, Enumerator};
+pub use crate::monitor::{Event, EventType, MonitorBuilder, MonitorSocket, Overflow, Reception};
+#[cfg(feature = "tokio")]
+pub use crate::monitor::AsyncMonitorSocket;
+pub use crate::watcher::{WatchEvents, Watcher};
@@ -1,4 +1,5 @@
 use std::{
+    convert::TryFrom,
     ffi::{CString, OsStr},
     fmt, io,
     ops::Deref,
@@ -6,6 +7,7 @@ use std::{
     ptr,
 };
 
+use libc::c_int;
 use mio::{event::Evented, unix::EventedFd, Poll, PollOpt, Ready, Token};
 
 use crate::{AsRaw, Context, Device, FromRawWithContext};
@@ -98,6 +100,20 @@ impl MonitorBuilder {
         })
     }
 
+    /// Sets the kernel socket's receive buffer size, in bytes.
+    ///
+    /// Requires `CAP_NET_ADMIN`. See [`MonitorSocket::receive_event`] for overflow detection.
+    pub fn set_receive_buffer_size(&mut self, size: usize) -> crate::Result<()> {
+        let size = match c_int::try_from(size) {
+            Ok(size) => size,
+            Err(_) => return Err(crate::error::from_errno(libc::EINVAL)),
+        };
+
+        crate::util::errno_to_result(unsafe {
+            crate::ffi::udev_monitor_set_receive_buffer_size(self.monitor, size)
+        })
+    }
+
     /// Listens for events matching the current filters.
     ///
     /// This method consumes the `Monitor`.
@@ -106,7 +122,10 @@ impl MonitorBuilder {
             crate::ffi::udev_monitor_enable_receiving(self.monitor)
         })?;
 
-        Ok(MonitorSocket { inner: self })
+        Ok(MonitorSocket {
+            inner: self,
+            filter: None,
+        })
     }
 }
 
@@ -120,6 +139,7 @@ impl MonitorBuilder {
 /// wait for new events.
 pub struct MonitorSocket {
     inner: MonitorBuilder,
+    filter: Option<Vec<EventType>>,
 }
 
 impl Clone for MonitorSocket {
@@ -131,6 +151,7 @@ impl Clone for MonitorSocket {
                     crate::ffi::udev_monitor_ref(self.inner.monitor),
                 )
             },
+            filter: self.filter.clone(),
         }
     }
 }
@@ -149,6 +170,7 @@ impl FromRawWithContext<crate::ffi::udev_monitor> for MonitorSocket {
     unsafe fn from_raw(context: &Context, ptr: *mut crate::ffi::udev_monitor) -> MonitorSocket {
         MonitorSocket {
             inner: MonitorBuilder::from_raw(context, ptr),
+            filter: None,
         }
     }
 }
@@ -161,20 +183,95 @@ impl AsRawFd for MonitorSocket {
 }
 
 impl Iterator for MonitorSocket {
-    type Item = Event;
+    type Item = Result<Event, Overflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.recv_event() {
+            Ok(Some(event)) => Some(Ok(event)),
+            Ok(None) => None,
+            Err(overflow) => Some(Err(overflow)),
+        }
+    }
+}
+
+/// The outcome of a single read from a [`MonitorSocket`].
+pub enum Reception {
+    /// A device event was received.
+    Device(Event),
 
-    fn next(&mut self) -> Option<Event> {
+    /// No event is currently available.
+    None,
+
+    /// The kernel's receive buffer overflowed; events were dropped before this one could be read.
+    Overflow,
+}
+
+impl MonitorSocket {
+    /// Receives a single event from the socket, distinguishing a dropped-event overflow from the
+    /// ordinary case of no event being currently available.
+    pub fn receive_event(&self) -> Reception {
         let ptr = unsafe { crate::ffi::udev_monitor_receive_device(self.inner.monitor) };
 
-        if ptr.is_null() {
-            None
-        } else {
+        if !ptr.is_null() {
             let device = unsafe { crate::Device::from_raw(&self.inner.context, ptr) };
-            Some(Event { device })
+            return Reception::Device(Event {
+                action: None,
+                device,
+            });
+        }
+
+        match io::Error::last_os_error().raw_os_error() {
+            Some(libc::ENOBUFS) => Reception::Overflow,
+            _ => Reception::None,
+        }
+    }
+
+    /// Restricts events produced by `recv_event` and this socket's `Iterator` to only the given
+    /// `EventType`s; other events are read off the socket and discarded internally.
+    pub fn set_event_filter(&mut self, types: &[EventType]) {
+        self.filter = Some(types.to_vec());
+    }
+
+    /// Receives a single event from the socket, applying the filter set by `set_event_filter`.
+    ///
+    /// Equivalent to `Iterator::next`, but takes `&self` so it can be called from contexts (e.g.
+    /// after a manual `poll`/`select` wakeup on the raw fd) that only have a shared reference.
+    /// Returns `Err(Overflow)` if the kernel receive buffer overflowed instead of folding that
+    /// into "no event available".
+    pub fn recv_event(&self) -> Result<Option<Event>, Overflow> {
+        loop {
+            match self.receive_event() {
+                Reception::Device(event) => {
+                    let matches = match &self.filter {
+                        Some(types) => types.contains(&event.event_type()),
+                        None => true,
+                    };
+
+                    if matches {
+                        return Ok(Some(event));
+                    }
+                }
+                Reception::None => return Ok(None),
+                Reception::Overflow => return Err(Overflow),
+            }
         }
     }
 }
 
+/// The kernel's receive buffer overflowed; events were dropped before they could be read.
+///
+/// Callers should re-run an `Enumerator` scan to rebuild their device list.
+#[derive(Debug, Clone, Copy)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("monitor receive buffer overflowed; events were dropped")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
 /// Types of events that can be received from udev.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EventType {
@@ -218,6 +315,10 @@ impl fmt::Display for EventType {
 
 /// An event that indicates a change in device state.
 pub struct Event {
+    /// Overrides the `EventType` that would otherwise be derived from the device's `ACTION`
+    /// property. Used for synthetic events, e.g. devices reported by [`crate::Watcher`] during
+    /// its initial scan, which have no `ACTION` property of their own.
+    action: Option<EventType>,
     device: Device,
 }
 
@@ -231,8 +332,21 @@ impl Deref for Event {
 }
 
 impl Event {
+    /// Creates a synthetic event for a device that didn't come from the monitor socket, e.g. one
+    /// reported by an `Enumerator` scan, overriding the `EventType` it reports.
+    pub(crate) fn new_synthetic(device: Device, event_type: EventType) -> Event {
+        Event {
+            action: Some(event_type),
+            device,
+        }
+    }
+
     /// Returns the `EventType` corresponding to this event.
     pub fn event_type(&self) -> EventType {
+        if let Some(action) = self.action {
+            return action;
+        }
+
         let value = match self.device.property_value("ACTION") {
             Some(s) => s.to_str(),
             None => None,
@@ -284,3 +398,53 @@ impl Evented for MonitorSocket {
         EventedFd(&self.as_raw_fd()).deregister(poll)
     }
 }
+
+/// An asynchronous wrapper around `MonitorSocket` that implements `futures::Stream`.
+///
+/// Requires the `tokio` feature.
+#[cfg(feature = "tokio")]
+pub struct AsyncMonitorSocket {
+    monitor: tokio::io::unix::AsyncFd<MonitorSocket>,
+}
+
+#[cfg(feature = "tokio")]
+impl AsyncMonitorSocket {
+    /// Wraps a `MonitorSocket` so it can be polled for events from an async context.
+    pub fn new(monitor: MonitorSocket) -> io::Result<Self> {
+        Ok(Self {
+            monitor: tokio::io::unix::AsyncFd::new(monitor)?,
+        })
+    }
+
+    /// Unwraps the socket, discarding the `AsyncFd` registration.
+    pub fn into_inner(self) -> MonitorSocket {
+        self.monitor.into_inner()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl futures::Stream for AsyncMonitorSocket {
+    type Item = io::Result<Event>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            let mut guard = futures::ready!(this.monitor.poll_read_ready(cx))?;
+
+            match guard.get_inner().recv_event() {
+                Ok(Some(event)) => return std::task::Poll::Ready(Some(Ok(event))),
+                Ok(None) => guard.clear_ready(),
+                Err(overflow) => {
+                    return std::task::Poll::Ready(Some(Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        overflow,
+                    ))))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::ffi::{OsStr, OsString};
+
+use crate::enumerator::{Devices, Enumerator};
+use crate::monitor::{Event, EventType, MonitorBuilder, MonitorSocket, Overflow};
+use crate::Context;
+
+/// Builds a combined view of devices matching a set of filters: every device already present,
+/// followed by live add/remove/change events.
+///
+/// Enables the monitor before scanning, so no device added in between is missed — though such a
+/// device may be reported twice (once from the scan, once as a live event); dedupe by devpath if
+/// exactly-once delivery matters.
+pub struct Watcher {
+    context: Context,
+    subsystems: Vec<OsString>,
+    tags: Vec<OsString>,
+    properties: Vec<(OsString, OsString)>,
+}
+
+impl Watcher {
+    /// Creates a new `Watcher` with no filters.
+    pub fn new(context: &Context) -> Self {
+        Watcher {
+            context: context.clone(),
+            subsystems: Vec::new(),
+            tags: Vec::new(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// Adds a filter that matches events and devices with the given subsystem.
+    pub fn match_subsystem<T: AsRef<OsStr>>(&mut self, subsystem: T) -> &mut Self {
+        self.subsystems.push(subsystem.as_ref().to_os_string());
+        self
+    }
+
+    /// Adds a filter that matches events and devices with the given tag.
+    pub fn match_tag<T: AsRef<OsStr>>(&mut self, tag: T) -> &mut Self {
+        self.tags.push(tag.as_ref().to_os_string());
+        self
+    }
+
+    /// Adds a filter that matches devices with the given property value.
+    ///
+    /// Property filters only apply to the initial scan; the kernel-side monitor socket has no
+    /// way to filter events by property value.
+    pub fn match_property<T: AsRef<OsStr>, U: AsRef<OsStr>>(
+        &mut self,
+        property: T,
+        value: U,
+    ) -> &mut Self {
+        self.properties
+            .push((property.as_ref().to_os_string(), value.as_ref().to_os_string()));
+        self
+    }
+
+    /// Enables the monitor, scans `/sys` for devices already present, and returns an iterator
+    /// that yields those devices as synthetic `Add` events before switching to live events.
+    pub fn listen(&self) -> crate::Result<WatchEvents> {
+        let mut monitor = MonitorBuilder::new(&self.context)?;
+        for subsystem in &self.subsystems {
+            monitor.match_subsystem(subsystem)?;
+        }
+        for tag in &self.tags {
+            monitor.match_tag(tag)?;
+        }
+        let socket = monitor.listen()?;
+
+        let mut enumerator = Enumerator::new(&self.context)?;
+        for subsystem in &self.subsystems {
+            enumerator.match_subsystem(subsystem)?;
+        }
+        for tag in &self.tags {
+            enumerator.match_tag(tag)?;
+        }
+        for (property, value) in &self.properties {
+            enumerator.match_property(property, value)?;
+        }
+        let devices = enumerator.scan_devices()?;
+
+        Ok(WatchEvents {
+            initial: Some(devices),
+            monitor: socket,
+        })
+    }
+}
+
+/// Iterator returned by [`Watcher::listen`].
+///
+/// Yields every device present at the time `listen` was called as a synthetic `EventType::Add`,
+/// then yields live events from the underlying `MonitorSocket`.
+pub struct WatchEvents {
+    initial: Option<Devices>,
+    monitor: MonitorSocket,
+}
+
+impl Iterator for WatchEvents {
+    type Item = Result<Event, Overflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(devices) = self.initial.as_mut() {
+            if let Some(device) = devices.next() {
+                return Some(Ok(Event::new_synthetic(device, EventType::Add)));
+            }
+            self.initial = None;
+        }
+
+        self.monitor.next()
+    }
+}